@@ -0,0 +1,335 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
+
+const BASE: u64 = 1_000_000_000;
+
+/// An arbitrary-precision signed integer, so [`Rational`](crate::numeric::Rational) numerators
+/// and denominators can grow across many STV counting stages without the silent wraparound or
+/// panic a fixed-width integer would eventually hit.
+///
+/// Represented as a sign flag plus a little-endian, base-1,000,000,000 magnitude with no trailing
+/// (most significant) zero limbs; zero is always stored with `negative: false` and an empty
+/// magnitude.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    /// The additive identity, `0`.
+    pub fn zero() -> Self {
+        BigInt {
+            negative: false,
+            magnitude: Vec::new(),
+        }
+    }
+
+    /// Converts a machine-width integer into a `BigInt`.
+    pub fn from_i128(value: i128) -> Self {
+        let negative = value < 0;
+        let mut remaining = value.unsigned_abs();
+        let mut magnitude = Vec::new();
+
+        while remaining > 0 {
+            magnitude.push((remaining % BASE as u128) as u32);
+            remaining /= BASE as u128;
+        }
+
+        BigInt { negative, magnitude }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The absolute value.
+    pub fn abs(&self) -> Self {
+        BigInt {
+            negative: false,
+            magnitude: self.magnitude.clone(),
+        }
+    }
+
+    /// Approximates this value as an `f64`, for reporting and tie-break history where exactness no
+    /// longer matters.
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0.0, |value, &limb| value * BASE as f64 + limb as f64);
+
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Truncating division, returning `(quotient, remainder)` such that
+    /// `self == quotient.clone() * other.clone() + remainder` and the remainder takes the sign of
+    /// `self`, matching Rust's built-in integer division. Panics if `other` is zero.
+    pub fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        assert!(!other.is_zero(), "division by zero");
+
+        let (quotient_magnitude, remainder_magnitude) =
+            Self::divmod_magnitude(&self.magnitude, &other.magnitude);
+
+        (
+            Self::normalized(self.negative != other.negative, quotient_magnitude),
+            Self::normalized(self.negative, remainder_magnitude),
+        )
+    }
+
+    fn normalized(negative: bool, magnitude: Vec<u32>) -> Self {
+        let magnitude = Self::trimmed(magnitude);
+        let negative = negative && !magnitude.is_empty();
+
+        BigInt { negative, magnitude }
+    }
+
+    fn trimmed(mut magnitude: Vec<u32>) -> Vec<u32> {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+
+        magnitude
+    }
+
+    fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+        a.len()
+            .cmp(&b.len())
+            .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+    }
+
+    fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+        let mut carry = 0u64;
+
+        for i in 0..a.len().max(b.len()) {
+            let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+            result.push((sum % BASE) as u32);
+            carry = sum / BASE;
+        }
+
+        if carry > 0 {
+            result.push(carry as u32);
+        }
+
+        Self::trimmed(result)
+    }
+
+    /// Subtracts `b` from `a`, assuming `a >= b`.
+    fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut result = Vec::with_capacity(a.len());
+        let mut borrow = 0i64;
+
+        for (i, &limb) in a.iter().enumerate() {
+            let mut diff = limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+
+            if diff < 0 {
+                diff += BASE as i64;
+                borrow = 1;
+            } else {
+                borrow = 0;
+            }
+
+            result.push(diff as u32);
+        }
+
+        Self::trimmed(result)
+    }
+
+    fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+
+        let mut result = vec![0u64; a.len() + b.len()];
+
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, &y) in b.iter().enumerate() {
+                let product = x as u64 * y as u64 + result[i + j] + carry;
+                result[i + j] = product % BASE;
+                carry = product / BASE;
+            }
+
+            let mut k = i + b.len();
+            while carry > 0 {
+                let sum = result[k] + carry;
+                result[k] = sum % BASE;
+                carry = sum / BASE;
+                k += 1;
+            }
+        }
+
+        Self::trimmed(result.into_iter().map(|limb| limb as u32).collect())
+    }
+
+    /// Schoolbook long division, processing `a`'s limbs from most to least significant and
+    /// binary-searching each resulting digit of the quotient.
+    fn divmod_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+        if Self::cmp_magnitude(a, b) == Ordering::Less {
+            return (Vec::new(), a.to_vec());
+        }
+
+        let mut quotient = vec![0u32; a.len()];
+        let mut remainder: Vec<u32> = Vec::new();
+
+        for i in (0..a.len()).rev() {
+            remainder.insert(0, a[i]);
+            remainder = Self::trimmed(remainder);
+
+            let (mut low, mut high) = (0u64, BASE - 1);
+            while low < high {
+                let mid = (low + high).div_ceil(2);
+                let candidate = Self::mul_magnitude(b, &[mid as u32]);
+                if Self::cmp_magnitude(&candidate, &remainder) != Ordering::Greater {
+                    low = mid;
+                } else {
+                    high = mid - 1;
+                }
+            }
+
+            quotient[i] = low as u32;
+            remainder = Self::sub_magnitude(&remainder, &Self::mul_magnitude(b, &[low as u32]));
+        }
+
+        (Self::trimmed(quotient), remainder)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::normalized(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::normalized(
+                self.negative,
+                Self::add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+
+        match Self::cmp_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Equal => BigInt::zero(),
+            Ordering::Greater => {
+                BigInt::normalized(self.negative, Self::sub_magnitude(&self.magnitude, &other.magnitude))
+            }
+            Ordering::Less => {
+                BigInt::normalized(other.negative, Self::sub_magnitude(&other.magnitude, &self.magnitude))
+            }
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: BigInt) -> BigInt {
+        self + (-other)
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: BigInt) -> BigInt {
+        BigInt::normalized(
+            self.negative != other.negative,
+            Self::mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.negative != other.negative {
+            return if self.negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        let magnitude_order = Self::cmp_magnitude(&self.magnitude, &other.magnitude);
+        if self.negative {
+            magnitude_order.reverse()
+        } else {
+            magnitude_order
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_from_i128_and_back_to_f64() {
+        assert_eq!(BigInt::from_i128(-42).to_f64(), -42.0);
+        assert_eq!(BigInt::from_i128(0).to_f64(), 0.0);
+    }
+
+    #[test]
+    fn adds_and_subtracts_across_the_limb_boundary() {
+        let a = BigInt::from_i128(999_999_999);
+        let b = BigInt::from_i128(1);
+
+        assert_eq!(a.clone() + b.clone(), BigInt::from_i128(1_000_000_000));
+        assert_eq!((a + b) - BigInt::from_i128(1_000_000_000), BigInt::zero());
+    }
+
+    #[test]
+    fn adds_numbers_of_opposite_sign() {
+        assert_eq!(
+            BigInt::from_i128(5) + BigInt::from_i128(-8),
+            BigInt::from_i128(-3)
+        );
+    }
+
+    #[test]
+    fn multiplies_beyond_i128_range() {
+        // i128::MAX is about 1.7e38; squaring a 30-digit number overflows it.
+        let huge = BigInt::from_i128(123_456_789_012_345_678_901_234_567_890_i128);
+
+        let squared = huge.clone() * huge;
+        assert!(squared.to_f64() > 1.0e38);
+    }
+
+    #[test]
+    fn div_rem_matches_integer_division() {
+        let (quotient, remainder) = BigInt::from_i128(17).div_rem(&BigInt::from_i128(5));
+
+        assert_eq!(quotient, BigInt::from_i128(3));
+        assert_eq!(remainder, BigInt::from_i128(2));
+    }
+
+    #[test]
+    fn orders_by_true_value_across_signs_and_magnitudes() {
+        assert!(BigInt::from_i128(-5) < BigInt::from_i128(-1));
+        assert!(BigInt::from_i128(-1) < BigInt::from_i128(0));
+        assert!(BigInt::from_i128(0) < BigInt::from_i128(1));
+        assert!(BigInt::from_i128(1_000_000_000) > BigInt::from_i128(999_999_999));
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt;
+
+/// A ranked-ballot election parsed from the BLT file format.
+///
+/// `ballots` pairs each ballot's weight with its preference list, where each preference is a
+/// 1-based index into `candidates`.
+#[derive(Debug, PartialEq)]
+pub struct Election {
+    pub candidates: Vec<String>,
+    pub seats: usize,
+    pub ballots: Vec<(usize, Vec<usize>)>,
+}
+
+/// An error encountered while parsing a BLT file.
+#[derive(Debug, PartialEq)]
+pub enum BltParseError {
+    /// The `<num_candidates> <num_seats>` header line was not exactly two numbers.
+    MalformedHeader(String),
+    /// A ballot line was not a weight followed by preferences terminated by `0`.
+    MalformedBallot(String),
+    /// A ballot referenced a preference index outside `1..=num_candidates`.
+    OutOfRangePreference { index: i64, num_candidates: usize },
+    /// A candidate name or the election title was not a `"quoted string"`.
+    UnquotedString(String),
+    /// The file ended before all expected sections were read.
+    UnexpectedEof,
+}
+
+impl fmt::Display for BltParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BltParseError::MalformedHeader(line) => {
+                write!(f, "expected \"<num_candidates> <num_seats>\", got: {line}")
+            }
+            BltParseError::MalformedBallot(line) => {
+                write!(
+                    f,
+                    "expected a ballot weight and preferences terminated by 0, got: {line}"
+                )
+            }
+            BltParseError::OutOfRangePreference {
+                index,
+                num_candidates,
+            } => write!(
+                f,
+                "preference index {index} is out of range for {num_candidates} candidates"
+            ),
+            BltParseError::UnquotedString(line) => {
+                write!(f, "expected a \"quoted string\", got: {line}")
+            }
+            BltParseError::UnexpectedEof => write!(f, "unexpected end of BLT input"),
+        }
+    }
+}
+
+impl std::error::Error for BltParseError {}
+
+/// Parses the standard BLT ranked-ballot election format.
+///
+/// The first line is `<num_candidates> <num_seats>`. Each following ballot line is a weight
+/// followed by space-separated 1-based candidate preference indices, terminated by `0`; a `0` on
+/// its own line ends the ballot section. Then `num_candidates` quoted candidate name strings
+/// follow, and finally a quoted election title, which is validated but not retained.
+pub fn parse_blt(input: &str) -> Result<Election, BltParseError> {
+    let mut lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    let header = lines.next().ok_or(BltParseError::UnexpectedEof)?;
+    let mut header_fields = header.split_whitespace();
+    let num_candidates: usize = header_fields
+        .next()
+        .and_then(|field| field.parse().ok())
+        .ok_or_else(|| BltParseError::MalformedHeader(header.to_string()))?;
+    let seats: usize = header_fields
+        .next()
+        .and_then(|field| field.parse().ok())
+        .ok_or_else(|| BltParseError::MalformedHeader(header.to_string()))?;
+    if header_fields.next().is_some() {
+        return Err(BltParseError::MalformedHeader(header.to_string()));
+    }
+
+    let mut ballots = Vec::new();
+    loop {
+        let line = lines.next().ok_or(BltParseError::UnexpectedEof)?;
+        let fields: Vec<i64> = line
+            .split_whitespace()
+            .map(|field| field.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| BltParseError::MalformedBallot(line.to_string()))?;
+
+        if fields == [0] {
+            break;
+        }
+
+        let (weight, rest) = fields
+            .split_first()
+            .ok_or_else(|| BltParseError::MalformedBallot(line.to_string()))?;
+        let (terminator, preferences) = rest
+            .split_last()
+            .ok_or_else(|| BltParseError::MalformedBallot(line.to_string()))?;
+        if *terminator != 0 || *weight < 0 {
+            return Err(BltParseError::MalformedBallot(line.to_string()));
+        }
+
+        let mut preference_indices = Vec::with_capacity(preferences.len());
+        for &index in preferences {
+            if index < 1 || index as usize > num_candidates {
+                return Err(BltParseError::OutOfRangePreference {
+                    index,
+                    num_candidates,
+                });
+            }
+            preference_indices.push(index as usize);
+        }
+
+        ballots.push((*weight as usize, preference_indices));
+    }
+
+    let mut candidates = Vec::with_capacity(num_candidates);
+    for _ in 0..num_candidates {
+        let line = lines.next().ok_or(BltParseError::UnexpectedEof)?;
+        candidates.push(unquote(line)?);
+    }
+
+    let title_line = lines.next().ok_or(BltParseError::UnexpectedEof)?;
+    unquote(title_line)?;
+
+    Ok(Election {
+        candidates,
+        seats,
+        ballots,
+    })
+}
+
+/// Strips the surrounding double quotes from a `"quoted string"` line.
+fn unquote(line: &str) -> Result<String, BltParseError> {
+    if line.len() >= 2 && line.starts_with('"') && line.ends_with('"') {
+        Ok(line[1..line.len() - 1].to_string())
+    } else {
+        Err(BltParseError::UnquotedString(line.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_well_formed_election() {
+        let blt = "\
+            3 1\n\
+            1 1 2 0\n\
+            1 2 1 0\n\
+            1 3 0\n\
+            0\n\
+            \"Alice\"\n\
+            \"Bob\"\n\
+            \"Carol\"\n\
+            \"Example Election\"\n";
+
+        let election = parse_blt(blt).expect("should parse");
+
+        assert_eq!(
+            election,
+            Election {
+                candidates: vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()],
+                seats: 1,
+                ballots: vec![(1, vec![1, 2]), (1, vec![2, 1]), (1, vec![3])],
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_header() {
+        assert_eq!(
+            parse_blt("not a header\n"),
+            Err(BltParseError::MalformedHeader("not a header".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_ballot_not_terminated_by_zero() {
+        let blt = "2 1\n1 1 2\n";
+
+        assert_eq!(
+            parse_blt(blt),
+            Err(BltParseError::MalformedBallot("1 1 2".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_preference() {
+        let blt = "2 1\n1 3 0\n0\n\"A\"\n\"B\"\n\"Title\"\n";
+
+        assert_eq!(
+            parse_blt(blt),
+            Err(BltParseError::OutOfRangePreference {
+                index: 3,
+                num_candidates: 2
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unquoted_candidate_name() {
+        let blt = "1 1\n1 1 0\n0\nA\n\"Title\"\n";
+
+        assert_eq!(
+            parse_blt(blt),
+            Err(BltParseError::UnquotedString("A".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        assert_eq!(
+            parse_blt("2 1\n1 1 0\n0\n\"A\"\n"),
+            Err(BltParseError::UnexpectedEof)
+        );
+    }
+}
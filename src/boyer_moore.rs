@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 /// Implements the Booyer Moore voting algorithm.
 ///
 /// This algorithm finds if any candidate has votes exceeding 50%.
@@ -44,6 +46,63 @@ pub fn boyer_moore(votes: Vec<&str>) -> Option<&str> {
     }
 }
 
+/// Implements the Misra-Gries generalization of the Boyer-Moore voting algorithm.
+///
+/// This algorithm finds every candidate with votes exceeding `n/k`, where `n` is the number of
+/// votes. There can be at most `k - 1` such candidates. It has a linear O(n) time complexity and
+/// O(k) space complexity.
+///
+/// We keep a map of at most `k - 1` candidates and their running vote tallies. For each vote, if
+/// the candidate is already tracked, we add to its tally. If not and we are tracking fewer than
+/// `k - 1` candidates, we start tracking it with a tally of 1. Otherwise, we decrement every
+/// tracked candidate's tally, dropping any that reach 0.
+///
+/// This first pass only yields a superset of the true winners, so we perform a second pass that
+/// re-counts the actual occurrences of each surviving candidate and keeps only those exceeding
+/// the `n/k` threshold.
+///
+/// # Panics
+///
+/// Panics if `k` is less than 2, since the threshold `n/k` is undefined for `k < 2`.
+pub fn boyer_moore_k(votes: Vec<&str>, k: usize) -> Vec<&str> {
+    assert!(k >= 2, "k must be at least 2");
+
+    if votes.is_empty() {
+        return vec![];
+    }
+
+    // Running tallies for the at most `k - 1` tracked candidates.
+    let mut tallies: HashMap<&str, usize> = HashMap::with_capacity(k - 1);
+
+    for vote in votes.iter() {
+        if let Some(count) = tallies.get_mut(vote) {
+            // Our tracked `vote` gets another vote.
+            *count += 1;
+        } else if tallies.len() < k - 1 {
+            // We have room to track a new candidate.
+            tallies.insert(vote, 1);
+        } else {
+            // No room left, decrement every tracked candidate's tally, dropping any that hit 0.
+            tallies.retain(|_, count| {
+                *count -= 1;
+                *count > 0
+            });
+        }
+    }
+
+    // For a `candidate` to win, they must get more than `n/k` of the votes.
+    let threshold = ((votes.len() as f64) / (k as f64)).floor() as usize;
+
+    tallies
+        .keys()
+        .filter(|&&candidate| {
+            let candidate_count = votes.iter().filter(|&vote| *vote == candidate).count();
+            candidate_count > threshold
+        })
+        .copied()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +154,44 @@ mod tests {
 
         assert!(boyer_moore(votes).is_none());
     }
+
+    #[test]
+    #[should_panic]
+    fn boyer_moore_k_rejects_k_less_than_2() {
+        boyer_moore_k(vec!["a"], 1);
+    }
+
+    #[test]
+    fn boyer_moore_k_no_votes_has_no_candidates() {
+        assert!(boyer_moore_k(vec![], 3).is_empty());
+    }
+
+    #[test]
+    fn boyer_moore_k_finds_majority_candidate() {
+        let votes = vec!["a", "a", "a", "b"];
+        assert_eq!(boyer_moore_k(votes, 2), vec!["a"]);
+    }
+
+    #[test]
+    fn boyer_moore_k_finds_multiple_candidates_above_threshold() {
+        // n = 9, k = 3, threshold = 3. "a" and "b" both exceed 3 occurrences.
+        let votes = vec!["a", "a", "a", "a", "b", "b", "b", "b", "c"];
+        let mut result = boyer_moore_k(votes, 3);
+        result.sort();
+
+        assert_eq!(result, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn boyer_moore_k_excludes_ties_at_exactly_n_over_k() {
+        // n = 6, k = 3, threshold = 2. "a" has exactly 2 occurrences, which does not exceed it.
+        let votes = vec!["a", "a", "b", "c", "d", "e"];
+        assert!(!boyer_moore_k(votes, 3).contains(&"a"));
+    }
+
+    #[test]
+    fn boyer_moore_k_no_candidate_above_threshold() {
+        let votes = vec!["a", "b", "c", "d", "e", "f"];
+        assert!(boyer_moore_k(votes, 3).is_empty());
+    }
 }
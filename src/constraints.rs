@@ -0,0 +1,336 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// A minimum and/or maximum number of seats that may be won by candidates in one category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CategoryLimit {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+}
+
+/// Per-candidate category memberships and per-category seat limits, consulted by the STV counter
+/// to apply the guard/doom method: a candidate is "guarded" (protected from exclusion) once
+/// excluding any more of their category's hopefuls would make a minimum unreachable, and "doomed"
+/// (excluded at the next opportunity) once their category has already filled its maximum.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Constraints {
+    candidate_categories: HashMap<String, Vec<String>>,
+    category_limits: HashMap<String, CategoryLimit>,
+}
+
+impl Constraints {
+    pub fn new(
+        candidate_categories: HashMap<String, Vec<String>>,
+        category_limits: HashMap<String, CategoryLimit>,
+    ) -> Self {
+        Constraints {
+            candidate_categories,
+            category_limits,
+        }
+    }
+
+    fn categories_of(&self, candidate: &str) -> &[String] {
+        self.candidate_categories
+            .get(candidate)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Given the candidates elected so far and those still continuing, returns the sets of
+    /// guarded and doomed candidates among `continuing`.
+    pub(crate) fn guard_and_doom<'a>(
+        &self,
+        elected: &[&str],
+        continuing: &HashSet<&'a str>,
+    ) -> (HashSet<&'a str>, HashSet<&'a str>) {
+        let mut guarded = HashSet::new();
+        let mut doomed = HashSet::new();
+
+        for (category, limit) in &self.category_limits {
+            let elected_in_category = elected
+                .iter()
+                .filter(|candidate| self.categories_of(candidate).iter().any(|c| c == category))
+                .count();
+            let hopefuls: Vec<&'a str> = continuing
+                .iter()
+                .copied()
+                .filter(|candidate| self.categories_of(candidate).iter().any(|c| c == category))
+                .collect();
+
+            if let Some(max) = limit.max {
+                if elected_in_category >= max {
+                    doomed.extend(hopefuls.iter().copied());
+                }
+            }
+
+            if let Some(min) = limit.min {
+                if elected_in_category + hopefuls.len() <= min {
+                    guarded.extend(hopefuls.iter().copied());
+                }
+            }
+        }
+
+        (guarded, doomed)
+    }
+}
+
+/// An error encountered while parsing a constraints file.
+#[derive(Debug, PartialEq)]
+pub enum ConstraintParseError {
+    /// A line did not begin with `candidate` or `category`.
+    UnknownLine(String),
+    /// A `candidate` or `category` line was missing its `"quoted name"`.
+    MissingName(String),
+    /// A `category` line had a token that was not `min=<n>` or `max=<n>`.
+    MalformedLimit(String),
+    /// The same candidate was declared more than once.
+    DuplicateCandidate(String),
+    /// The same category's limits were declared more than once.
+    DuplicateCategory(String),
+}
+
+impl fmt::Display for ConstraintParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintParseError::UnknownLine(line) => {
+                write!(
+                    f,
+                    "expected a \"candidate\" or \"category\" line, got: {line}"
+                )
+            }
+            ConstraintParseError::MissingName(line) => {
+                write!(f, "expected a \"quoted name\", got: {line}")
+            }
+            ConstraintParseError::MalformedLimit(line) => write!(
+                f,
+                "expected category limits as min=<n> and/or max=<n>, got: {line}"
+            ),
+            ConstraintParseError::DuplicateCandidate(candidate) => {
+                write!(f, "candidate \"{candidate}\" was declared more than once")
+            }
+            ConstraintParseError::DuplicateCategory(category) => {
+                write!(f, "category \"{category}\" was declared more than once")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConstraintParseError {}
+
+/// Parses a simple category-constraints file.
+///
+/// Each non-blank line is either:
+/// - `candidate "<name>" "<category>" ...` mapping one candidate to zero or more categories, or
+/// - `category "<name>" [min=<n>] [max=<n>]` giving a minimum and/or maximum number of seats
+///   that category's candidates may win.
+pub fn parse_constraints(input: &str) -> Result<Constraints, ConstraintParseError> {
+    let mut candidate_categories: HashMap<String, Vec<String>> = HashMap::new();
+    let mut category_limits: HashMap<String, CategoryLimit> = HashMap::new();
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+
+        match keyword {
+            "candidate" => {
+                let mut tokens = parse_quoted_tokens(rest, line)?;
+                if tokens.is_empty() {
+                    return Err(ConstraintParseError::MissingName(line.to_string()));
+                }
+                let candidate = tokens.remove(0);
+
+                if candidate_categories
+                    .insert(candidate.clone(), tokens)
+                    .is_some()
+                {
+                    return Err(ConstraintParseError::DuplicateCandidate(candidate));
+                }
+            }
+            "category" => {
+                let (name, rest) = split_quoted_prefix(rest.trim_start(), line)?;
+                let mut limit = CategoryLimit::default();
+
+                for token in rest.split_whitespace() {
+                    if let Some(value) = token.strip_prefix("min=") {
+                        limit.min =
+                            Some(value.parse().map_err(|_| {
+                                ConstraintParseError::MalformedLimit(line.to_string())
+                            })?);
+                    } else if let Some(value) = token.strip_prefix("max=") {
+                        limit.max =
+                            Some(value.parse().map_err(|_| {
+                                ConstraintParseError::MalformedLimit(line.to_string())
+                            })?);
+                    } else {
+                        return Err(ConstraintParseError::MalformedLimit(line.to_string()));
+                    }
+                }
+
+                if category_limits.insert(name.clone(), limit).is_some() {
+                    return Err(ConstraintParseError::DuplicateCategory(name));
+                }
+            }
+            _ => return Err(ConstraintParseError::UnknownLine(line.to_string())),
+        }
+    }
+
+    Ok(Constraints::new(candidate_categories, category_limits))
+}
+
+/// Parses zero or more `"quoted tokens"` from `rest`, reporting errors against the original
+/// `line` for context.
+fn parse_quoted_tokens(rest: &str, line: &str) -> Result<Vec<String>, ConstraintParseError> {
+    let mut tokens = Vec::new();
+    let mut remainder = rest.trim_start();
+
+    while !remainder.is_empty() {
+        let (token, next) = split_quoted_prefix(remainder, line)?;
+        tokens.push(token);
+        remainder = next.trim_start();
+    }
+
+    Ok(tokens)
+}
+
+/// Splits a leading `"quoted string"` off the front of `input`, reporting errors against the
+/// original `line` for context.
+fn split_quoted_prefix<'a>(
+    input: &'a str,
+    line: &str,
+) -> Result<(String, &'a str), ConstraintParseError> {
+    if !input.starts_with('"') {
+        return Err(ConstraintParseError::MissingName(line.to_string()));
+    }
+
+    let closing = input[1..]
+        .find('"')
+        .ok_or_else(|| ConstraintParseError::MissingName(line.to_string()))?;
+    let end = 1 + closing;
+
+    Ok((input[1..end].to_string(), &input[end + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_candidates_and_category_limits() {
+        let input = "\
+            candidate \"Alice\" \"North\" \"Youth\"\n\
+            candidate \"Bob\" \"South\"\n\
+            category \"North\" min=1 max=2\n\
+            category \"South\" max=1\n";
+
+        let constraints = parse_constraints(input).expect("should parse");
+
+        let mut continuing = HashSet::new();
+        continuing.insert("Alice");
+        continuing.insert("Bob");
+
+        let (guarded, doomed) = constraints.guard_and_doom(&[], &continuing);
+        assert_eq!(guarded, HashSet::from(["Alice"]));
+        assert!(doomed.is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_line() {
+        assert_eq!(
+            parse_constraints("elector \"Alice\"\n"),
+            Err(ConstraintParseError::UnknownLine(
+                "elector \"Alice\"".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_limit() {
+        assert_eq!(
+            parse_constraints("category \"North\" min=one\n"),
+            Err(ConstraintParseError::MalformedLimit(
+                "category \"North\" min=one".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_candidate() {
+        let input = "candidate \"Alice\" \"North\"\ncandidate \"Alice\" \"South\"\n";
+
+        assert_eq!(
+            parse_constraints(input),
+            Err(ConstraintParseError::DuplicateCandidate(
+                "Alice".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn doom_triggers_once_a_categorys_maximum_is_elected() {
+        let mut candidate_categories = HashMap::new();
+        candidate_categories.insert("Alice".to_string(), vec!["North".to_string()]);
+        candidate_categories.insert("Bob".to_string(), vec!["North".to_string()]);
+        let mut category_limits = HashMap::new();
+        category_limits.insert(
+            "North".to_string(),
+            CategoryLimit {
+                min: None,
+                max: Some(1),
+            },
+        );
+        let constraints = Constraints::new(candidate_categories, category_limits);
+
+        let mut continuing = HashSet::new();
+        continuing.insert("Bob");
+
+        let (guarded, doomed) = constraints.guard_and_doom(&["Alice"], &continuing);
+        assert!(guarded.is_empty());
+        assert_eq!(doomed, HashSet::from(["Bob"]));
+    }
+
+    #[test]
+    fn guard_triggers_once_hopefuls_exactly_cover_a_categorys_minimum() {
+        let mut candidate_categories = HashMap::new();
+        candidate_categories.insert("Alice".to_string(), vec!["North".to_string()]);
+        candidate_categories.insert("Bob".to_string(), vec!["North".to_string()]);
+        let mut category_limits = HashMap::new();
+        category_limits.insert(
+            "North".to_string(),
+            CategoryLimit {
+                min: Some(2),
+                max: None,
+            },
+        );
+        let constraints = Constraints::new(candidate_categories, category_limits);
+
+        let mut continuing = HashSet::new();
+        continuing.insert("Alice");
+        continuing.insert("Bob");
+
+        let (guarded, doomed) = constraints.guard_and_doom(&[], &continuing);
+        assert_eq!(guarded, HashSet::from(["Alice", "Bob"]));
+        assert!(doomed.is_empty());
+    }
+
+    #[test]
+    fn candidates_outside_any_constrained_category_are_unaffected() {
+        let mut candidate_categories = HashMap::new();
+        candidate_categories.insert("Alice".to_string(), vec!["North".to_string()]);
+        let mut category_limits = HashMap::new();
+        category_limits.insert(
+            "North".to_string(),
+            CategoryLimit {
+                min: None,
+                max: Some(0),
+            },
+        );
+        let constraints = Constraints::new(candidate_categories, category_limits);
+
+        let mut continuing = HashSet::new();
+        continuing.insert("Alice");
+        continuing.insert("Carol");
+
+        let (guarded, doomed) = constraints.guard_and_doom(&[], &continuing);
+        assert!(guarded.is_empty());
+        assert_eq!(doomed, HashSet::from(["Alice"]));
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+
+/// An error encountered while converting a CSV ballot export to BLT.
+#[derive(Debug, PartialEq)]
+pub enum CsvToBltError {
+    /// The CSV had no header row of candidate names.
+    EmptyHeader,
+    /// A row's cell could not be parsed as an integer rank.
+    MalformedRank {
+        row: usize,
+        column: usize,
+        value: String,
+    },
+    /// A row did not have one cell per candidate in the header.
+    WrongColumnCount {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for CsvToBltError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvToBltError::EmptyHeader => {
+                write!(f, "CSV is missing a header row of candidate names")
+            }
+            CsvToBltError::MalformedRank { row, column, value } => write!(
+                f,
+                "row {row}, column {column}: expected a blank cell or an integer rank, got: {value}"
+            ),
+            CsvToBltError::WrongColumnCount {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {row}: expected {expected} columns to match the header, found {found}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvToBltError {}
+
+/// Converts a CSV ballot export into a BLT ballot stream.
+///
+/// The header row lists candidate names. Each following row gives one voter's ranking as an
+/// integer rank per candidate cell, with blank cells meaning that candidate is unranked. Each
+/// row's candidates are sorted by ascending rank to build its BLT preference list, and the
+/// candidate count is inferred from the header. `seats` is not present in the CSV and is taken
+/// as a parameter.
+pub fn csv_to_blt(csv: &str, seats: usize) -> Result<String, CsvToBltError> {
+    let mut lines = csv.lines().map(str::trim);
+
+    let header = lines
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or(CsvToBltError::EmptyHeader)?;
+    let candidates: Vec<&str> = header.split(',').map(str::trim).collect();
+    let num_candidates = candidates.len();
+
+    let mut ballot_lines = String::new();
+    for (row_index, row) in lines.enumerate() {
+        let cells: Vec<&str> = row.split(',').map(str::trim).collect();
+        if cells.len() != num_candidates {
+            return Err(CsvToBltError::WrongColumnCount {
+                row: row_index + 2,
+                expected: num_candidates,
+                found: cells.len(),
+            });
+        }
+
+        let mut ranked: Vec<(u32, usize)> = Vec::new();
+        for (column_index, &cell) in cells.iter().enumerate() {
+            if cell.is_empty() {
+                continue;
+            }
+
+            let rank: u32 = cell.parse().map_err(|_| CsvToBltError::MalformedRank {
+                row: row_index + 2,
+                column: column_index + 1,
+                value: cell.to_string(),
+            })?;
+
+            // 1-based candidate index, matching the BLT preference format.
+            ranked.push((rank, column_index + 1));
+        }
+        ranked.sort_by_key(|&(rank, _)| rank);
+
+        // Each CSV row is one voter, so every ballot line carries a weight of 1.
+        ballot_lines.push_str("1 ");
+        for (_, candidate_index) in &ranked {
+            ballot_lines.push_str(&candidate_index.to_string());
+            ballot_lines.push(' ');
+        }
+        ballot_lines.push_str("0\n");
+    }
+
+    let mut blt = format!("{num_candidates} {seats}\n");
+    blt.push_str(&ballot_lines);
+    blt.push_str("0\n");
+    for candidate in &candidates {
+        blt.push_str(&format!("\"{candidate}\"\n"));
+    }
+    blt.push_str("\"Converted Election\"\n");
+
+    Ok(blt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blt::parse_blt;
+
+    #[test]
+    fn converts_ranked_rows_to_blt() {
+        let csv = "Alice,Bob,Carol\n2,1,3\n1,,2\n";
+
+        let blt = csv_to_blt(csv, 1).expect("should convert");
+        let election = parse_blt(&blt).expect("output should be valid BLT");
+
+        assert_eq!(election.candidates, vec!["Alice", "Bob", "Carol"]);
+        assert_eq!(election.seats, 1);
+        assert_eq!(election.ballots, vec![(1, vec![2, 1, 3]), (1, vec![1, 3])]);
+    }
+
+    #[test]
+    fn blank_ranks_are_skipped() {
+        let csv = "Alice,Bob\n,\n";
+
+        let blt = csv_to_blt(csv, 1).expect("should convert");
+        let election = parse_blt(&blt).expect("output should be valid BLT");
+
+        assert_eq!(election.ballots, vec![(1, vec![])]);
+    }
+
+    #[test]
+    fn rejects_empty_header() {
+        assert_eq!(csv_to_blt("\n1,2\n", 1), Err(CsvToBltError::EmptyHeader));
+    }
+
+    #[test]
+    fn rejects_malformed_rank() {
+        assert_eq!(
+            csv_to_blt("Alice,Bob\nfirst,2\n", 1),
+            Err(CsvToBltError::MalformedRank {
+                row: 2,
+                column: 1,
+                value: "first".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_column_count() {
+        assert_eq!(
+            csv_to_blt("Alice,Bob\n1\n", 1),
+            Err(CsvToBltError::WrongColumnCount {
+                row: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub mod bigint;
+pub mod blt;
+pub mod boyer_moore;
+pub mod constraints;
+pub mod csv_to_blt;
+pub mod numeric;
+pub mod stv;
+pub mod ties;
@@ -1,10 +1,16 @@
-mod boyer_moore;
-
 use std::fs;
 
-use crate::boyer_moore::boyer_moore;
+use voting_algorithms::blt::{parse_blt, Election};
+use voting_algorithms::boyer_moore::boyer_moore;
+use voting_algorithms::constraints::{parse_constraints, Constraints};
+use voting_algorithms::csv_to_blt::csv_to_blt;
+use voting_algorithms::stv::count_stv;
+use voting_algorithms::ties::{TieBreakStrategy, TieBreaker};
 
 const VOTES_INPUT_FILE: &str = "votes.txt";
+const BLT_INPUT_FILE: &str = "votes.blt";
+const CSV_INPUT_FILE: &str = "votes.csv";
+const CONSTRAINTS_INPUT_FILE: &str = "constraints.txt";
 
 fn main() {
     println!(
@@ -22,4 +28,81 @@ fn main() {
     let result = boyer_moore(votes);
 
     println!("Vote Winner: {}", result.unwrap_or("No Winner"));
+
+    println!(
+        "\nRunning Single Transferable Vote counting on ranked ballots in {}",
+        BLT_INPUT_FILE
+    );
+
+    let blt_file_contents: String = fs::read_to_string(BLT_INPUT_FILE)
+        .expect("The ranked ballots input file should exist at votes.blt");
+
+    let election =
+        parse_blt(&blt_file_contents).expect("votes.blt should be a valid BLT election file");
+
+    let tie_breaker = TieBreaker::new(
+        vec![
+            TieBreakStrategy::Backwards,
+            TieBreakStrategy::Forwards,
+            TieBreakStrategy::Random,
+        ],
+        0,
+    );
+
+    let constraints: Option<Constraints> =
+        fs::read_to_string(CONSTRAINTS_INPUT_FILE)
+            .ok()
+            .map(|contents| {
+                parse_constraints(&contents)
+                    .expect("constraints.txt should be a valid constraints file")
+            });
+
+    let winners = count_stv::<f64>(
+        resolve_ballots(&election),
+        election.seats,
+        Some(&tie_breaker),
+        constraints.as_ref(),
+    )
+    .expect("ties and category constraints should be resolved without conflict");
+
+    println!("Elected: {}", winners.join(", "));
+
+    if let Ok(csv_file_contents) = fs::read_to_string(CSV_INPUT_FILE) {
+        println!(
+            "\nConverting CSV ballots in {} to BLT and running STV counting",
+            CSV_INPUT_FILE
+        );
+
+        let converted_blt = csv_to_blt(&csv_file_contents, election.seats)
+            .expect("votes.csv should be a valid CSV ballot export");
+        let csv_election =
+            parse_blt(&converted_blt).expect("BLT converted from votes.csv should be valid");
+
+        let csv_winners = count_stv::<f64>(
+            resolve_ballots(&csv_election),
+            csv_election.seats,
+            Some(&tie_breaker),
+            constraints.as_ref(),
+        )
+        .expect("ties and category constraints should be resolved without conflict");
+
+        println!("Elected: {}", csv_winners.join(", "));
+    }
+}
+
+/// Converts each ballot's preference indices into candidate names, pairing the result with the
+/// ballot's weight for `count_stv` to carry as its initial transfer value.
+fn resolve_ballots(election: &Election) -> Vec<(usize, Vec<&str>)> {
+    election
+        .ballots
+        .iter()
+        .map(|(weight, preferences)| {
+            let ranking: Vec<&str> = preferences
+                .iter()
+                .map(|&index| election.candidates[index - 1].as_str())
+                .collect();
+
+            (*weight, ranking)
+        })
+        .collect()
 }
@@ -0,0 +1,269 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::bigint::BigInt;
+
+/// The arithmetic used for ballot weights, vote tallies, and quotas throughout vote counting.
+///
+/// Different implementations trade off performance, rounding behavior, and reproducibility:
+/// - [`f64`] is fast but accumulates floating-point rounding error across many surplus transfers.
+/// - [`FixedPoint`] rounds every operation to a fixed number of decimal places, matching
+///   jurisdictions that specify rounding rules for official counts.
+/// - [`Rational`] is exact, avoiding any rounding error at the cost of growing numerators and
+///   denominators, and is the right choice for reproducible, tie-sensitive official counts.
+///
+/// Implementations need only be [`Clone`] rather than [`Copy`]: [`Rational`] holds
+/// arbitrary-precision numerators and denominators that do not fit in a fixed-size, cheaply
+/// copyable value.
+pub trait Number:
+    Clone
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The additive identity, `0`.
+    fn zero() -> Self;
+
+    /// Converts a whole number, such as a ballot count or quota, into this number type.
+    fn from_usize(value: usize) -> Self;
+
+    /// Approximates this value as an `f64`, for reporting and tie-break history where exactness
+    /// no longer matters.
+    fn to_f64(&self) -> f64;
+}
+
+impl Number for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn from_usize(value: usize) -> Self {
+        value as f64
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+}
+
+/// A fixed-point number with a fixed number of `DECIMALS` decimal places.
+///
+/// Multiplication and division truncate their result toward zero to the nearest representable
+/// value (rounding down for non-negative results), matching
+/// jurisdiction-specified rounding rules for official vote counts.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct FixedPoint<const DECIMALS: u32>(i64);
+
+impl<const DECIMALS: u32> FixedPoint<DECIMALS> {
+    fn scale() -> i64 {
+        10i64.pow(DECIMALS)
+    }
+}
+
+impl<const DECIMALS: u32> Add for FixedPoint<DECIMALS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        FixedPoint(self.0 + other.0)
+    }
+}
+
+impl<const DECIMALS: u32> Sub for FixedPoint<DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        FixedPoint(self.0 - other.0)
+    }
+}
+
+impl<const DECIMALS: u32> Mul for FixedPoint<DECIMALS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        FixedPoint((self.0 * other.0) / Self::scale())
+    }
+}
+
+impl<const DECIMALS: u32> Div for FixedPoint<DECIMALS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        FixedPoint((self.0 * Self::scale()) / other.0)
+    }
+}
+
+impl<const DECIMALS: u32> Number for FixedPoint<DECIMALS> {
+    fn zero() -> Self {
+        FixedPoint(0)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        FixedPoint(value as i64 * Self::scale())
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.0 as f64 / Self::scale() as f64
+    }
+}
+
+/// An exact rational number, represented as a numerator over a strictly positive denominator
+/// kept in lowest terms.
+///
+/// Both are [`BigInt`]s rather than machine integers: cross-multiplying denominators at every
+/// surplus transfer compounds across an STV count's many stages, and a fixed-width integer
+/// eventually overflows even for an ordinary mid-size election.
+#[derive(Debug, Clone)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "denominator must not be 0");
+
+        Self::reduced(BigInt::from_i128(numerator), BigInt::from_i128(denominator))
+    }
+
+    /// Builds a `Rational` from a numerator and (possibly negative) denominator, normalizing the
+    /// sign onto the numerator and reducing to lowest terms.
+    fn reduced(numerator: BigInt, denominator: BigInt) -> Self {
+        let (numerator, denominator) = if denominator.is_negative() {
+            (-numerator, -denominator)
+        } else {
+            (numerator, denominator)
+        };
+
+        let divisor = gcd(numerator.abs(), denominator.abs());
+        let divisor = if divisor.is_zero() {
+            BigInt::from_i128(1)
+        } else {
+            divisor
+        };
+
+        let (numerator, _) = numerator.div_rem(&divisor);
+        let (denominator, _) = denominator.div_rem(&divisor);
+
+        Rational {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+fn gcd(mut a: BigInt, mut b: BigInt) -> BigInt {
+    while !b.is_zero() {
+        let (_, remainder) = a.div_rem(&b);
+        a = b;
+        b = remainder;
+    }
+
+    a
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self.numerator.clone() * other.denominator.clone()
+            == other.numerator.clone() * self.denominator.clone()
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.numerator.clone() * other.denominator.clone())
+            .partial_cmp(&(other.numerator.clone() * self.denominator.clone()))
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let numerator = self.numerator.clone() * other.denominator.clone()
+            + other.numerator * self.denominator.clone();
+
+        Rational::reduced(numerator, self.denominator * other.denominator)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let numerator = self.numerator.clone() * other.denominator.clone()
+            - other.numerator * self.denominator.clone();
+
+        Rational::reduced(numerator, self.denominator * other.denominator)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Rational::reduced(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Rational::reduced(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
+
+impl Number for Rational {
+    fn zero() -> Self {
+        Rational::new(0, 1)
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Rational::new(value as i128, 1)
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.numerator.to_f64() / self.denominator.to_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_point_rounds_down_to_its_decimal_places() {
+        let two_thirds = FixedPoint::<2>::from_usize(2) / FixedPoint::<2>::from_usize(3);
+
+        // 2/3 = 0.6666..., rounded down to 2 decimal places is 0.66.
+        assert_eq!(two_thirds, FixedPoint::<2>(66));
+    }
+
+    #[test]
+    fn rational_reduces_to_lowest_terms() {
+        let two_thirds = Rational::new(4, 6);
+
+        assert_eq!(two_thirds, Rational::new(2, 3));
+    }
+
+    #[test]
+    fn rational_is_exact_across_repeated_arithmetic() {
+        let one_third = Rational::new(1, 1) / Rational::new(3, 1);
+        let sum = one_third.clone() + one_third.clone() + one_third;
+
+        assert_eq!(sum, Rational::new(1, 1));
+    }
+
+    #[test]
+    fn rational_orders_by_true_value() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+    }
+}
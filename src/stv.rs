@@ -0,0 +1,546 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::constraints::Constraints;
+use crate::numeric::Number;
+use crate::ties::{StageTally, TieBreaker, UnresolvedTie};
+
+/// An error encountered while counting an STV election.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CountError {
+    /// Every configured tie-break strategy left candidates tied.
+    Tie(UnresolvedTie),
+    /// The category constraints guard and doom the same candidate(s) at once, or every continuing
+    /// candidate is guarded when one must be excluded, so no outcome can satisfy every minimum and
+    /// maximum simultaneously.
+    UnsatisfiableConstraints { candidates: Vec<String> },
+}
+
+impl From<UnresolvedTie> for CountError {
+    fn from(tie: UnresolvedTie) -> Self {
+        CountError::Tie(tie)
+    }
+}
+
+/// A ranked ballot's running transfer pile.
+///
+/// `preferences` is the ballot's full ranking, and `weight` is the fractional value this ballot
+/// currently carries. A ballot starts at the weight it was given (the number of identical votes it
+/// represents) and is reduced every time it is swept up in a winning candidate's surplus transfer.
+struct Ballot<'a, N: Number> {
+    preferences: Vec<&'a str>,
+    weight: N,
+}
+
+/// Finds the first candidate in `preferences` that is still `continuing`, i.e. this ballot's
+/// current resting place.
+fn current_candidate<'a>(
+    preferences: &[&'a str],
+    continuing: &HashSet<&'a str>,
+) -> Option<&'a str> {
+    preferences
+        .iter()
+        .find(|candidate| continuing.contains(*candidate))
+        .copied()
+}
+
+/// Sums each continuing candidate's weighted vote total across all ballots resting on them.
+fn tally<'a, N: Number>(
+    piles: &[Ballot<'a, N>],
+    continuing: &HashSet<&'a str>,
+) -> HashMap<&'a str, N> {
+    let mut tallies: HashMap<&str, N> = continuing.iter().map(|&c| (c, N::zero())).collect();
+
+    for ballot in piles {
+        if let Some(candidate) = current_candidate(&ballot.preferences, continuing) {
+            let total = tallies.get_mut(candidate).expect("candidate is continuing");
+            *total = total.clone() + ballot.weight.clone();
+        }
+    }
+
+    tallies
+}
+
+/// Reduces the weight of every ballot currently resting on `winner` by the Gregory transfer
+/// factor `surplus / votes`, so their transferable value moves on to the next continuing
+/// preference at the reduced value.
+fn transfer_surplus<'a, N: Number>(
+    piles: &mut [Ballot<'a, N>],
+    winner: &'a str,
+    surplus: N,
+    votes: N,
+    continuing: &HashSet<&'a str>,
+) {
+    let factor = surplus / votes;
+
+    for ballot in piles.iter_mut() {
+        if current_candidate(&ballot.preferences, continuing) == Some(winner) {
+            ballot.weight = ballot.weight.clone() * factor.clone();
+        }
+    }
+}
+
+/// The candidate(s) sharing the best tally, where `is_better(votes, best)` decides which tally wins
+/// (`>` picks the highest, `<` the lowest), found by folding over a stage's tallies.
+fn extremes_by<'a, N: Number>(
+    tallies: &HashMap<&'a str, N>,
+    is_better: impl Fn(N, N) -> bool,
+) -> Vec<&'a str> {
+    let best = tallies
+        .values()
+        .cloned()
+        .fold(None, |best: Option<N>, votes| match best {
+            Some(best) if !is_better(votes.clone(), best.clone()) => Some(best),
+            _ => Some(votes),
+        });
+
+    match best {
+        Some(best) => tallies
+            .iter()
+            .filter(|&(_, votes)| *votes == best)
+            .map(|(&candidate, _)| candidate)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Converts a stage's tallies into the `f64` snapshot a [`TieBreaker`] consults for the
+/// `Backwards`/`Forwards` strategies.
+fn snapshot<N: Number>(tallies: &HashMap<&str, N>) -> StageTally {
+    tallies
+        .iter()
+        .map(|(&candidate, votes)| (candidate.to_string(), votes.to_f64()))
+        .collect()
+}
+
+/// Picks a single candidate out of a tied group, consulting `tie_breaker` if more than one
+/// candidate is tied and a tie-breaker was configured. Falls back to the first candidate found
+/// when no tie-breaker is given, preserving the previous untie-breaking behavior.
+fn pick<'a>(
+    tied: Vec<&'a str>,
+    tie_breaker: Option<&TieBreaker>,
+    history: &[StageTally],
+) -> Result<&'a str, CountError> {
+    match tied.as_slice() {
+        [] => unreachable!("there is always at least one continuing candidate to pick from"),
+        [only] => Ok(only),
+        tied => match tie_breaker {
+            Some(tie_breaker) => Ok(tie_breaker.resolve(tied, history)?),
+            None => Ok(tied[0]),
+        },
+    }
+}
+
+/// Counts a multi-seat Single Transferable Vote (STV) election using the Scottish Weighted
+/// Inclusive Gregory rules, returning the elected candidates.
+///
+/// Each ballot pairs a ranking of candidates in order of preference with a `weight`, the number of
+/// identical physical votes it represents; a single vote is simply weight `1`. Carrying the weight
+/// as the ballot's initial transfer value lets a large election with many identical rankings (as
+/// BLT's per-line weight is meant to express) be counted without materializing one entry per
+/// physical vote. A candidate is elected once their weighted vote total meets or exceeds the
+/// Droop quota `floor(valid_ballots / (seats + 1)) + 1`.
+/// An elected candidate's surplus above the quota is transferred to the next continuing
+/// preference on each of their ballots, with every transferring ballot's weight reduced by the
+/// factor `surplus / total_transferable_votes` (the Gregory method). When no candidate meets
+/// quota, the lowest-placed continuing candidate is excluded and their ballots move on to the
+/// next continuing preference at their current weight. This repeats until all seats are filled or
+/// the number of continuing candidates equals the number of remaining seats, at which point they
+/// are all elected.
+///
+/// Counting is generic over the [`Number`] arithmetic used for ballot weights and the quota, so
+/// callers can trade off speed, jurisdiction-specified rounding, or exactness.
+///
+/// Whenever two or more candidates are tied for election or exclusion, `tie_breaker` (if given)
+/// resolves which one is picked; without one, the first candidate found is picked arbitrarily.
+/// Returns an [`UnresolvedTie`] if a tie-breaker was given but every one of its strategies also
+/// tied.
+///
+/// If `constraints` is given, its category minimums and maximums are enforced throughout the
+/// count using the guard/doom method: before each stage, any candidate whose category has already
+/// filled its maximum is "doomed" and excluded ahead of the normal lowest-candidate exclusion, and
+/// any candidate whose category needs every one of its remaining hopefuls to reach its minimum is
+/// "guarded" and cannot be excluded. Returns [`CountError::UnsatisfiableConstraints`] if a
+/// candidate is ever guarded and doomed at once, or if every remaining candidate is guarded with
+/// no one left to exclude.
+pub fn count_stv<'a, N: Number>(
+    ballots: Vec<(usize, Vec<&'a str>)>,
+    seats: usize,
+    tie_breaker: Option<&TieBreaker>,
+    constraints: Option<&Constraints>,
+) -> Result<Vec<&'a str>, CountError> {
+    let total_weight: usize = ballots.iter().map(|(weight, _)| weight).sum();
+    let quota = N::from_usize(total_weight / (seats + 1) + 1);
+
+    let mut continuing: HashSet<&'a str> = HashSet::new();
+    for (_, preferences) in &ballots {
+        for &candidate in preferences {
+            continuing.insert(candidate);
+        }
+    }
+
+    let mut piles: Vec<Ballot<'a, N>> = ballots
+        .into_iter()
+        .map(|(weight, preferences)| Ballot {
+            preferences,
+            weight: N::from_usize(weight),
+        })
+        .collect();
+
+    let mut elected: Vec<&'a str> = Vec::new();
+    let mut history: Vec<StageTally> = Vec::new();
+
+    while elected.len() < seats && !continuing.is_empty() {
+        let remaining_seats = seats - elected.len();
+
+        let (guarded, doomed) = match constraints {
+            Some(constraints) => constraints.guard_and_doom(&elected, &continuing),
+            None => (HashSet::new(), HashSet::new()),
+        };
+
+        let conflicted: Vec<String> = guarded
+            .intersection(&doomed)
+            .map(|c| c.to_string())
+            .collect();
+        if !conflicted.is_empty() {
+            return Err(CountError::UnsatisfiableConstraints {
+                candidates: conflicted,
+            });
+        }
+
+        // Electing every continuing candidate outright is only safe once none of them are
+        // doomed; a doomed candidate must still be excluded first, even if that leaves fewer
+        // continuing candidates than remaining seats.
+        if continuing.len() <= remaining_seats && doomed.is_empty() {
+            elected.extend(continuing.iter().copied());
+            break;
+        }
+
+        let tallies = tally(&piles, &continuing);
+        history.push(snapshot(&tallies));
+
+        let doomed_tallies: HashMap<&str, N> = tallies
+            .iter()
+            .filter(|(candidate, _)| doomed.contains(*candidate))
+            .map(|(&candidate, votes)| (candidate, votes.clone()))
+            .collect();
+
+        if !doomed_tallies.is_empty() {
+            let losers = extremes_by(&doomed_tallies, |votes, best| votes < best);
+            let loser = pick(losers, tie_breaker, &history)?;
+
+            continuing.remove(loser);
+            continue;
+        }
+
+        let frontrunners = extremes_by(&tallies, |votes, best| votes > best)
+            .into_iter()
+            .filter(|candidate| tallies[candidate] >= quota)
+            .collect::<Vec<_>>();
+
+        if frontrunners.is_empty() {
+            let eligible_tallies: HashMap<&str, N> = tallies
+                .iter()
+                .filter(|(candidate, _)| !guarded.contains(*candidate))
+                .map(|(&candidate, votes)| (candidate, votes.clone()))
+                .collect();
+            let losers = extremes_by(&eligible_tallies, |votes, best| votes < best);
+            if losers.is_empty() {
+                return Err(CountError::UnsatisfiableConstraints {
+                    candidates: guarded.iter().map(|c| c.to_string()).collect(),
+                });
+            }
+            let loser = pick(losers, tie_breaker, &history)?;
+
+            continuing.remove(loser);
+        } else {
+            let winner = pick(frontrunners, tie_breaker, &history)?;
+            let votes = tallies[winner].clone();
+
+            let surplus = votes.clone() - quota.clone();
+            transfer_surplus(&mut piles, winner, surplus, votes, &continuing);
+
+            elected.push(winner);
+            continuing.remove(winner);
+        }
+    }
+
+    Ok(elected)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::constraints::CategoryLimit;
+    use crate::numeric::{FixedPoint, Rational};
+    use crate::ties::TieBreakStrategy;
+
+    #[test]
+    fn no_ballots_elects_no_one() {
+        assert!(count_stv::<f64>(vec![], 1, None, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn single_seat_majority_winner() {
+        let ballots = vec![
+            (1, vec!["a"]),
+            (1, vec!["a"]),
+            (1, vec!["a"]),
+            (1, vec!["b"]),
+        ];
+
+        assert_eq!(count_stv::<f64>(ballots, 1, None, None).unwrap(), vec!["a"]);
+    }
+
+    #[test]
+    fn surplus_transfers_to_next_preference() {
+        // Droop quota for 2 seats / 7 ballots is floor(7/3) + 1 = 3.
+        // "a" exceeds quota with 5 votes; their surplus of 2 transfers onward at a factor of
+        // 2/5, leaving "b" and "c" short of quota. "c" is then excluded and "b" wins the
+        // remaining seat as the sole continuing candidate.
+        let ballots = vec![
+            (1, vec!["a", "b"]),
+            (1, vec!["a", "b"]),
+            (1, vec!["a", "b"]),
+            (1, vec!["a", "c"]),
+            (1, vec!["a", "c"]),
+            (1, vec!["b"]),
+            (1, vec!["c"]),
+        ];
+
+        let mut winners = count_stv::<f64>(ballots, 2, None, None).unwrap();
+        winners.sort();
+
+        assert_eq!(winners, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn lowest_candidate_is_excluded_when_no_one_meets_quota() {
+        // Droop quota for 1 seat / 5 ballots is floor(5/2) + 1 = 3. No one starts at quota
+        // ("a" 1, "b" 2, "c" 2), so "a" is excluded and its ballot lifts "c" to 3.
+        let ballots = vec![
+            (1, vec!["a", "c"]),
+            (1, vec!["b", "c"]),
+            (1, vec!["b", "c"]),
+            (1, vec!["c"]),
+            (1, vec!["c"]),
+        ];
+
+        assert_eq!(count_stv::<f64>(ballots, 1, None, None).unwrap(), vec!["c"]);
+    }
+
+    #[test]
+    fn continuing_candidates_matching_remaining_seats_are_all_elected() {
+        let ballots = vec![(1, vec!["a"]), (1, vec!["b"])];
+
+        let mut winners = count_stv::<f64>(ballots, 2, None, None).unwrap();
+        winners.sort();
+
+        assert_eq!(winners, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn rounded_arithmetic_can_lose_a_seat_that_exact_rationals_keep() {
+        // Droop quota for 2 seats / 8 ballots is floor(8/3) + 1 = 3.
+        // "a" exceeds quota with 5 votes; their surplus of 2 transfers onward at a factor of
+        // 2/5. "b" needs exactly that fractional surplus to reach quota for the second seat.
+        let ballots = vec![
+            (1, vec!["a", "b"]),
+            (1, vec!["a", "b"]),
+            (1, vec!["a", "b"]),
+            (1, vec!["a", "b"]),
+            (1, vec!["a", "b"]),
+            (1, vec!["b"]),
+            (1, vec!["c"]),
+            (1, vec!["c"]),
+        ];
+
+        // A 0-decimal fixed-point truncates the 2/5 transfer factor down to 0, so "b"'s surplus
+        // share vanishes entirely, "b" falls short of quota, and is excluded in "c"'s favor.
+        let mut rounded_winners =
+            count_stv::<FixedPoint<0>>(ballots.clone(), 2, None, None).unwrap();
+        rounded_winners.sort();
+        assert_eq!(rounded_winners, vec!["a", "c"]);
+
+        // Exact rational arithmetic keeps the fractional surplus, so "b" reaches quota exactly
+        // and keeps the seat.
+        let mut exact_winners = count_stv::<Rational>(ballots, 2, None, None).unwrap();
+        exact_winners.sort();
+        assert_eq!(exact_winners, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn exact_rational_arithmetic_handles_a_realistic_mid_size_election() {
+        // 5,000 ballots over 12 candidates and 6 seats is an ordinary mid-size election, not a
+        // contrived input, but it runs through enough surplus-transfer stages that naively
+        // cross-multiplying fixed-width denominators at every stage overflows even a 128-bit
+        // integer. `Rational`'s arbitrary-precision arithmetic must get through it intact.
+        let candidates: Vec<String> = (0..12).map(|i| format!("c{i}")).collect();
+        let ballots: Vec<(usize, Vec<&str>)> = (0..5_000)
+            .map(|i| {
+                let offset = i % candidates.len();
+                let ranking = (0..candidates.len())
+                    .map(|j| candidates[(offset + j) % candidates.len()].as_str())
+                    .collect();
+                (1, ranking)
+            })
+            .collect();
+
+        let winners = count_stv::<Rational>(ballots, 6, None, None).unwrap();
+
+        assert_eq!(winners.len(), 6);
+    }
+
+    #[test]
+    fn tie_breaker_decides_between_tied_candidates() {
+        // "a" and "b" both start with 1 vote each; at the only stage they are tied, so
+        // "Backwards" cannot decide and falls through to "Forwards", which also ties, so the
+        // seeded "Random" strategy makes the final call.
+        let ballots = vec![(1, vec!["a"]), (1, vec!["b"])];
+        let tie_breaker = TieBreaker::new(
+            vec![
+                TieBreakStrategy::Backwards,
+                TieBreakStrategy::Forwards,
+                TieBreakStrategy::Random,
+            ],
+            1,
+        );
+
+        let winner = count_stv::<f64>(ballots, 1, Some(&tie_breaker), None).unwrap();
+
+        assert_eq!(winner.len(), 1);
+        assert!(winner[0] == "a" || winner[0] == "b");
+        assert_eq!(tie_breaker.audit_log().len(), 1);
+    }
+
+    #[test]
+    fn unresolved_tie_is_surfaced_as_an_error() {
+        let ballots = vec![(1, vec!["a"]), (1, vec!["b"])];
+        let tie_breaker = TieBreaker::new(vec![TieBreakStrategy::Backwards], 0);
+
+        assert!(count_stv::<f64>(ballots, 1, Some(&tie_breaker), None).is_err());
+    }
+
+    fn solo_category(candidate: &str, limit: CategoryLimit) -> Constraints {
+        let mut candidate_categories = HashMap::new();
+        candidate_categories.insert(candidate.to_string(), vec!["category".to_string()]);
+        let mut category_limits = HashMap::new();
+        category_limits.insert("category".to_string(), limit);
+
+        Constraints::new(candidate_categories, category_limits)
+    }
+
+    #[test]
+    fn guard_keeps_a_guarded_candidate_from_ever_being_excluded() {
+        // "a" has the fewest first-preference votes and no further preferences, so the
+        // unconstrained count excludes it first and "c" goes on to win.
+        let ballots = vec![
+            (1, vec!["a"]),
+            (1, vec!["b"]),
+            (1, vec!["b"]),
+            (1, vec!["c"]),
+            (1, vec!["c"]),
+            (1, vec!["c"]),
+        ];
+
+        let mut unconstrained = count_stv::<f64>(ballots.clone(), 1, None, None).unwrap();
+        unconstrained.sort();
+        assert_eq!(unconstrained, vec!["c"]);
+
+        // Guarding "a" (the sole hopeful in a category needing at least one seat) protects it
+        // from every exclusion instead: "b" and "c" are excluded in turn, leaving "a" as the
+        // sole continuing candidate, who is then elected outright.
+        let constraints = solo_category(
+            "a",
+            CategoryLimit {
+                min: Some(1),
+                max: None,
+            },
+        );
+
+        let guarded = count_stv::<f64>(ballots, 1, None, Some(&constraints)).unwrap();
+        assert_eq!(guarded, vec!["a"]);
+    }
+
+    #[test]
+    fn doom_excludes_a_doomed_candidate_even_with_the_most_first_preference_votes() {
+        // "b" has the most first-preference votes and would otherwise win a seat, but its
+        // category has already filled its maximum, so it is excluded regardless of its tally.
+        let ballots = vec![
+            (1, vec!["a"]),
+            (1, vec!["b"]),
+            (1, vec!["b"]),
+            (1, vec!["b"]),
+            (1, vec!["c"]),
+        ];
+
+        let constraints = solo_category(
+            "b",
+            CategoryLimit {
+                min: None,
+                max: Some(0),
+            },
+        );
+
+        let mut winners = count_stv::<f64>(ballots, 2, None, Some(&constraints)).unwrap();
+        winners.sort();
+
+        assert_eq!(winners, vec!["a", "c"]);
+    }
+
+    #[test]
+    fn doom_is_consulted_even_when_continuing_candidates_would_otherwise_all_be_elected() {
+        // With only "a" and "b" continuing and 2 seats, the "elect all remaining" shortcut would
+        // otherwise fire immediately and hand "b" a seat before their doomed status is ever
+        // checked. "b"'s category is already full, so they must be excluded instead, leaving "a"
+        // as the sole winner with the second seat going unfilled.
+        let ballots = vec![(1, vec!["a"]), (1, vec!["b"]), (1, vec!["b"])];
+
+        let constraints = solo_category(
+            "b",
+            CategoryLimit {
+                min: None,
+                max: Some(0),
+            },
+        );
+
+        let winners = count_stv::<f64>(ballots, 2, None, Some(&constraints)).unwrap();
+
+        assert_eq!(winners, vec!["a"]);
+    }
+
+    #[test]
+    fn guarding_and_dooming_the_same_candidate_is_reported_as_unsatisfiable() {
+        let mut candidate_categories = HashMap::new();
+        candidate_categories.insert(
+            "a".to_string(),
+            vec!["must_seat".to_string(), "full".to_string()],
+        );
+        let mut category_limits = HashMap::new();
+        category_limits.insert(
+            "must_seat".to_string(),
+            CategoryLimit {
+                min: Some(1),
+                max: None,
+            },
+        );
+        category_limits.insert(
+            "full".to_string(),
+            CategoryLimit {
+                min: None,
+                max: Some(0),
+            },
+        );
+        let constraints = Constraints::new(candidate_categories, category_limits);
+
+        let ballots = vec![(1, vec!["a"]), (1, vec!["b"])];
+
+        assert_eq!(
+            count_stv::<f64>(ballots, 1, None, Some(&constraints)),
+            Err(CountError::UnsatisfiableConstraints {
+                candidates: vec!["a".to_string()]
+            })
+        );
+    }
+}
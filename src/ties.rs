@@ -0,0 +1,254 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// A single tie-break strategy, tried in sequence by a [`TieBreaker`] until one is decisive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TieBreakStrategy {
+    /// Prefer whoever was ahead at the earliest previous stage where the tied candidates'
+    /// tallies differed.
+    Backwards,
+    /// Prefer whoever was ahead at the most recent previous stage where the tied candidates'
+    /// tallies differed.
+    Forwards,
+    /// Break the tie with a seeded pseudo-random choice, reproducible given the same seed.
+    Random,
+}
+
+/// Every configured [`TieBreakStrategy`] also left the candidates tied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnresolvedTie {
+    pub candidates: Vec<String>,
+}
+
+/// A snapshot of every continuing candidate's tally at one counting stage, used to look up "who
+/// was ahead" for the `Backwards`/`Forwards` strategies.
+pub type StageTally = HashMap<String, f64>;
+
+/// Records which strategy broke a tie and how, for auditability of the count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TieBreakRecord {
+    pub candidates: Vec<String>,
+    pub winner: String,
+    pub strategy: TieBreakStrategy,
+}
+
+/// Resolves ties between candidates with equal vote totals using a configurable, ordered list of
+/// strategies, trying each in turn until one separates the tied candidates.
+pub struct TieBreaker {
+    strategies: Vec<TieBreakStrategy>,
+    seed: u64,
+    audit_log: RefCell<Vec<TieBreakRecord>>,
+}
+
+impl TieBreaker {
+    /// Builds a tie-breaker that tries `strategies` in order, falling through to the next
+    /// strategy whenever the current one still leaves a tie. `seed` drives the `Random` strategy
+    /// so that results are reproducible given the same seed.
+    pub fn new(strategies: Vec<TieBreakStrategy>, seed: u64) -> Self {
+        TieBreaker {
+            strategies,
+            seed,
+            audit_log: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Every tie this breaker has resolved so far, in the order they were resolved.
+    pub fn audit_log(&self) -> Vec<TieBreakRecord> {
+        self.audit_log.borrow().clone()
+    }
+
+    /// Resolves a tie among `candidates`, who all share the current stage's tally, given the
+    /// history of prior stages' tallies in chronological order (oldest first).
+    pub fn resolve<'a>(
+        &self,
+        candidates: &[&'a str],
+        history: &[StageTally],
+    ) -> Result<&'a str, UnresolvedTie> {
+        for &strategy in &self.strategies {
+            if let Some(winner) = self.apply(strategy, candidates, history) {
+                self.audit_log.borrow_mut().push(TieBreakRecord {
+                    candidates: candidates.iter().map(|c| c.to_string()).collect(),
+                    winner: winner.to_string(),
+                    strategy,
+                });
+
+                return Ok(winner);
+            }
+        }
+
+        Err(UnresolvedTie {
+            candidates: candidates.iter().map(|c| c.to_string()).collect(),
+        })
+    }
+
+    fn apply<'a>(
+        &self,
+        strategy: TieBreakStrategy,
+        candidates: &[&'a str],
+        history: &[StageTally],
+    ) -> Option<&'a str> {
+        match strategy {
+            TieBreakStrategy::Backwards => Self::resolve_by_history(candidates, history.iter()),
+            TieBreakStrategy::Forwards => {
+                Self::resolve_by_history(candidates, history.iter().rev())
+            }
+            TieBreakStrategy::Random => Self::resolve_randomly(candidates, self.seed),
+        }
+    }
+
+    /// Walks `stages` in the given order, returning the first candidate found to be strictly
+    /// ahead of every other tied candidate at a stage, or `None` if they were tied at every
+    /// stage visited.
+    fn resolve_by_history<'a, 'b>(
+        candidates: &[&'a str],
+        stages: impl Iterator<Item = &'b StageTally>,
+    ) -> Option<&'a str> {
+        for stage in stages {
+            let mut leader: Option<(&'a str, f64)> = None;
+            let mut tied = false;
+
+            for &candidate in candidates {
+                let tally = *stage.get(candidate).unwrap_or(&0.0);
+
+                leader = match leader {
+                    None => Some((candidate, tally)),
+                    Some((_, leader_tally)) if tally > leader_tally => {
+                        tied = false;
+                        Some((candidate, tally))
+                    }
+                    Some((_, leader_tally)) if tally == leader_tally => {
+                        tied = true;
+                        leader
+                    }
+                    _ => leader,
+                };
+            }
+
+            if !tied {
+                return leader.map(|(candidate, _)| candidate);
+            }
+        }
+
+        None
+    }
+
+    /// Picks a candidate using a small, deterministic xorshift so the same seed always picks the
+    /// same candidate from the same tied set, regardless of the order `candidates` happens to be
+    /// given in (callers may build that order from a `HashMap`'s iteration, which varies between
+    /// runs even for identical input).
+    fn resolve_randomly<'a>(candidates: &[&'a str], seed: u64) -> Option<&'a str> {
+        let mut sorted: Vec<&'a str> = candidates.to_vec();
+        sorted.sort_unstable();
+
+        let mut state = seed ^ 0x9E37_79B9_7F4A_7C15;
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        sorted.get(state as usize % sorted.len()).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(pairs: &[(&str, f64)]) -> StageTally {
+        pairs.iter().map(|&(c, v)| (c.to_string(), v)).collect()
+    }
+
+    #[test]
+    fn backwards_prefers_earliest_differing_stage() {
+        let history = vec![
+            stage(&[("a", 5.0), ("b", 3.0)]),
+            stage(&[("a", 4.0), ("b", 4.0)]),
+        ];
+        let tie_breaker = TieBreaker::new(vec![TieBreakStrategy::Backwards], 0);
+
+        assert_eq!(tie_breaker.resolve(&["a", "b"], &history), Ok("a"));
+    }
+
+    #[test]
+    fn forwards_prefers_most_recent_differing_stage() {
+        let history = vec![
+            stage(&[("a", 3.0), ("b", 5.0)]),
+            stage(&[("a", 4.0), ("b", 4.0)]),
+        ];
+        let tie_breaker = TieBreaker::new(vec![TieBreakStrategy::Forwards], 0);
+
+        assert_eq!(tie_breaker.resolve(&["a", "b"], &history), Ok("b"));
+    }
+
+    #[test]
+    fn falls_through_to_the_next_strategy_when_history_is_also_tied() {
+        let history = vec![stage(&[("a", 4.0), ("b", 4.0)])];
+        let tie_breaker = TieBreaker::new(
+            vec![TieBreakStrategy::Backwards, TieBreakStrategy::Random],
+            42,
+        );
+
+        let winner = tie_breaker
+            .resolve(&["a", "b"], &history)
+            .expect("random should decide");
+        assert!(winner == "a" || winner == "b");
+    }
+
+    #[test]
+    fn random_is_reproducible_given_the_same_seed() {
+        let tie_breaker_one = TieBreaker::new(vec![TieBreakStrategy::Random], 7);
+        let tie_breaker_two = TieBreaker::new(vec![TieBreakStrategy::Random], 7);
+
+        assert_eq!(
+            tie_breaker_one.resolve(&["a", "b", "c"], &[]),
+            tie_breaker_two.resolve(&["a", "b", "c"], &[])
+        );
+    }
+
+    #[test]
+    fn random_is_reproducible_regardless_of_candidate_order() {
+        // `count_stv` builds the tied-candidate slice from a `HashMap`'s iteration order, which
+        // varies between runs for identical input. The same seed must still pick the same
+        // candidate no matter what order the tied slice arrives in.
+        let tie_breaker = TieBreaker::new(vec![TieBreakStrategy::Random], 42);
+
+        let forward = tie_breaker.resolve(&["a", "b", "c"], &[]);
+        let reversed = tie_breaker.resolve(&["c", "b", "a"], &[]);
+
+        assert_eq!(forward, reversed);
+    }
+
+    #[test]
+    fn unresolved_when_every_strategy_ties() {
+        let history = vec![stage(&[("a", 4.0), ("b", 4.0)])];
+        let tie_breaker = TieBreaker::new(
+            vec![TieBreakStrategy::Backwards, TieBreakStrategy::Forwards],
+            0,
+        );
+
+        assert_eq!(
+            tie_breaker.resolve(&["a", "b"], &history),
+            Err(UnresolvedTie {
+                candidates: vec!["a".to_string(), "b".to_string()]
+            })
+        );
+    }
+
+    #[test]
+    fn records_which_strategy_broke_the_tie() {
+        let history = vec![stage(&[("a", 5.0), ("b", 3.0)])];
+        let tie_breaker = TieBreaker::new(vec![TieBreakStrategy::Backwards], 0);
+
+        tie_breaker
+            .resolve(&["a", "b"], &history)
+            .expect("should resolve");
+
+        assert_eq!(
+            tie_breaker.audit_log(),
+            vec![TieBreakRecord {
+                candidates: vec!["a".to_string(), "b".to_string()],
+                winner: "a".to_string(),
+                strategy: TieBreakStrategy::Backwards,
+            }]
+        );
+    }
+}